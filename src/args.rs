@@ -14,6 +14,7 @@ pub enum Commands {
     Decode(DecodeArgs),
     Remove(RemoveArgs),
     Print(PrintArgs),
+    Batch(BatchArgs),
 }
 
 #[derive(Args, Debug, Clone)]
@@ -23,8 +24,13 @@ pub struct EncodeArgs {
     #[clap(value_parser)]
     pub chunk_type: String,
     #[clap(value_parser)]
-    pub message: String,
-    #[clap(value_parser)]
+    pub message: Option<String>,
+    /// Pack a named field into the chunk's payload as `name=value`. May be
+    /// repeated to store several fields in one chunk.
+    #[clap(long = "field")]
+    pub fields: Vec<String>,
+    /// Where to write the modified PNG. If omitted, the input is left untouched.
+    #[clap(long = "output", short = 'o')]
     pub output_file: Option<PathBuf>,
 }
 
@@ -34,6 +40,12 @@ pub struct DecodeArgs {
     pub file_path: PathBuf,
     #[clap(value_parser)]
     pub chunk_type: String,
+    /// Skip per-chunk CRC verification and trust the file is not corrupted.
+    #[clap(long)]
+    pub no_verify: bool,
+    /// Print only this named field from the chunk's payload, instead of every field.
+    #[clap(long)]
+    pub field: Option<String>,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -48,4 +60,18 @@ pub struct RemoveArgs {
 pub struct PrintArgs {
     #[clap(value_parser)]
     pub file_path: PathBuf,
+    /// Skip per-chunk CRC verification and trust the file is not corrupted.
+    #[clap(long)]
+    pub no_verify: bool,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct BatchArgs {
+    #[clap(value_parser)]
+    pub file_path: PathBuf,
+    #[clap(value_parser)]
+    pub output_file: PathBuf,
+    /// File of `chunk_type message` pairs, one per line. Reads from stdin if omitted.
+    #[clap(long)]
+    pub pairs_file: Option<PathBuf>,
 }
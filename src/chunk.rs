@@ -1,10 +1,8 @@
 use crc::{Crc, CRC_32_ISO_HDLC};
 use std::fmt::Display;
-use std::io::ErrorKind::Other;
-use std::io::{BufReader, Error, Read};
-use std::string::FromUtf8Error;
 
 use crate::chunk_type::ChunkType;
+use crate::error::PngError;
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct Chunk {
@@ -48,70 +46,86 @@ impl Chunk {
         self.crc
     }
 
-    pub fn data_as_string(&self) -> Result<String, FromUtf8Error> {
-        String::from_utf8(self.data.clone())
+    pub fn data_as_string(&self) -> Result<String, PngError> {
+        Ok(String::from_utf8(self.data.clone())?)
     }
 
-    pub fn as_bytes(&self) -> Vec<u8> {
-        let mut v: Vec<u8> = Vec::new();
+    /// Writes this chunk's bytes directly to `w`, without materializing the
+    /// whole chunk in an intermediate `Vec`.
+    pub fn write_to<W: std::io::Write>(&self, w: &mut W) -> Result<(), PngError> {
+        w.write_all(&self.length.to_be_bytes())?;
+        w.write_all(&self.chunk_type.bytes())?;
+        w.write_all(&self.data)?;
+        w.write_all(&self.crc.to_be_bytes())?;
 
-        for b in self.length.to_be_bytes() {
-            v.push(b);
-        }
-
-        for b in self.chunk_type.bytes() {
-            v.push(b);
-        }
-
-        for b in &self.data {
-            v.push(*b)
-        }
+        Ok(())
+    }
 
-        for b in self.crc.to_be_bytes() {
-            v.push(b);
-        }
+    /// Parses a chunk from `value`, taking its stored CRC at face value
+    /// instead of recomputing the crc32 over `chunk_type` and `data`. The
+    /// declared length is still bounds-checked against `value`, so this
+    /// cannot read out of bounds on malformed input — it just skips the
+    /// checksum pass, which is the expensive part per chunk.
+    pub fn from_bytes_unchecked(value: &[u8]) -> Result<Self, PngError> {
+        let (length, chunk_type, data, crc, _) = parse_chunk_fields(value)?;
 
-        v
+        Ok(Chunk {
+            length,
+            chunk_type,
+            data,
+            crc,
+        })
     }
 }
 
-impl TryFrom<&[u8]> for Chunk {
-    type Error = Error;
+/// Splits a chunk's header/data/trailer out of `value`, bounds-checking the
+/// declared length but not verifying the CRC. Returns the parsed fields plus
+/// the number of bytes the chunk occupies, for callers walking a buffer of
+/// concatenated chunks.
+fn parse_chunk_fields(value: &[u8]) -> Result<(u32, ChunkType, Vec<u8>, u32, usize), PngError> {
+    if value.len() < 12 {
+        return Err(PngError::TooShort);
+    }
 
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let mut reader = BufReader::new(value);
+    let length = u32::from_be_bytes(value[0..4].try_into().unwrap());
+
+    let chunk_type_bytes: [u8; 4] = value[4..8].try_into().unwrap();
+    let chunk_type = ChunkType::try_from(chunk_type_bytes)?;
 
-        let mut length_bytes: [u8; 4] = [0; 4];
-        reader.read_exact(&mut length_bytes)?;
-        let length = u32::from_be_bytes(length_bytes);
+    let data_end = 8usize
+        .checked_add(length as usize)
+        .ok_or(PngError::TooShort)?;
+    if value.len() < data_end + 4 {
+        return Err(PngError::TooShort);
+    }
 
-        let mut chunk_type_bytes: [u8; 4] = [0; 4];
-        reader.read_exact(&mut chunk_type_bytes)?;
-        let chunk_type = ChunkType::try_from(chunk_type_bytes)?;
+    let data = value[8..data_end].to_vec();
 
-        let mut data: Vec<u8> = vec![0u8; length as usize];
-        reader.read_exact(&mut data)?;
+    let crc_bytes: [u8; 4] = value[data_end..data_end + 4].try_into().unwrap();
+    let crc = u32::from_be_bytes(crc_bytes);
 
-        if data.len() != length as usize {
-            return Err(Error::new(
-                Other,
-                "length of data is not the same as the specified length",
-            ));
-        }
+    Ok((length, chunk_type, data, crc, data_end + 4))
+}
 
-        let mut crc_bytes: [u8; 4] = [0; 4];
-        reader.read_exact(&mut crc_bytes)?;
-        let crc = u32::from_be_bytes(crc_bytes);
+impl TryFrom<&[u8]> for Chunk {
+    type Error = PngError;
 
-        let bytes_vector: Vec<u8> = chunk_type_bytes
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let (length, chunk_type, data, crc, _) = parse_chunk_fields(value)?;
+
+        let bytes_vector: Vec<u8> = chunk_type
+            .bytes()
             .iter()
             .chain(data.as_slice().iter())
             .copied()
             .collect();
 
-        if crc != calculate_crc(bytes_vector.as_ref()) {
-            println!("{crc} {}", calculate_crc(bytes_vector.as_ref()));
-            return Err(Error::new(Other, "crc is incorrect"));
+        let expected = calculate_crc(bytes_vector.as_ref());
+        if crc != expected {
+            return Err(PngError::CrcMismatch {
+                expected,
+                found: crc,
+            });
         }
 
         let chunk = Chunk {
@@ -127,7 +141,7 @@ impl TryFrom<&[u8]> for Chunk {
 
 impl Display for Chunk {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.data_as_string().unwrap())
+        f.write_str(&String::from_utf8_lossy(&self.data))
     }
 }
 
@@ -247,6 +261,75 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    fn test_chunk_from_bytes_reports_crc_mismatch() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656333;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        match Chunk::try_from(chunk_data.as_ref()) {
+            Err(PngError::CrcMismatch { expected, found }) => {
+                assert_eq!(found, crc);
+                assert_eq!(expected, 2882656334);
+            }
+            other => panic!("expected CrcMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_chunk_from_bytes_reports_too_short() {
+        let chunk = Chunk::try_from(&[1, 2, 3][..]);
+        assert!(matches!(chunk, Err(PngError::TooShort)));
+    }
+
+    #[test]
+    fn test_chunk_from_bytes_unchecked_ignores_bad_crc() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656333; // deliberately wrong
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::from_bytes_unchecked(chunk_data.as_ref()).unwrap();
+        assert_eq!(chunk.length(), 42);
+        assert_eq!(chunk.crc(), crc);
+    }
+
+    #[test]
+    fn test_chunk_from_bytes_unchecked_still_bounds_checks() {
+        let chunk = Chunk::from_bytes_unchecked(&[0, 0, 0, 100, 82, 117, 83, 116]);
+        assert!(matches!(chunk, Err(PngError::TooShort)));
+    }
+
+    #[test]
+    fn test_chunk_write_to_round_trips() {
+        let chunk = testing_chunk();
+
+        let mut written = Vec::new();
+        chunk.write_to(&mut written).unwrap();
+
+        let round_tripped = Chunk::try_from(written.as_slice()).unwrap();
+        assert_eq!(round_tripped, chunk);
+    }
+
     #[test]
     pub fn test_chunk_trait_impls() {
         let data_length: u32 = 42;
@@ -1,46 +1,95 @@
 use std::convert::TryFrom;
 use std::fs;
-use std::io::Error;
+use std::io::{self, BufRead, BufReader, BufWriter};
 use std::str::FromStr;
 
-use crate::args::{DecodeArgs, EncodeArgs, PrintArgs, RemoveArgs};
+use crate::args::{BatchArgs, DecodeArgs, EncodeArgs, PrintArgs, RemoveArgs};
 use crate::chunk::Chunk;
 use crate::chunk_type::ChunkType;
-use crate::png::Png;
+use crate::error::PngError;
+use crate::payload::Payload;
+use crate::png::{Png, PngStream};
 
-pub fn run_encode(args: &EncodeArgs) -> Result<(), Error> {
+pub fn run_encode(args: &EncodeArgs) -> Result<(), PngError> {
     let file_bytes = fs::read(&args.file_path)?;
 
     let mut png = Png::try_from(file_bytes.as_slice())?;
 
     let chunk_type = ChunkType::from_str(&args.chunk_type)?;
-    let new_chunk = Chunk::new(chunk_type, args.message.as_bytes().to_vec());
+    let data = encode_payload(args)?;
+    let new_chunk = Chunk::new(chunk_type, data);
 
     png.append_chunk(new_chunk);
 
     if let Some(output_file) = &args.output_file {
-        fs::write(output_file, png.as_bytes())?;
+        let mut writer = BufWriter::new(fs::File::create(output_file)?);
+        png.write_to(&mut writer)?;
     }
 
     Ok(())
 }
 
-pub fn run_decode(args: &DecodeArgs) -> Result<(), Error> {
-    let file_bytes = fs::read(&args.file_path)?;
-
-    let png = Png::try_from(file_bytes.as_slice())?;
+/// Builds the bytes to store in the new chunk: a plain message, or, when
+/// `--field` was used at least once, a [`Payload`] packing every field.
+/// Exactly one of `message`/`--field` must be given.
+fn encode_payload(args: &EncodeArgs) -> Result<Vec<u8>, PngError> {
+    match (&args.message, args.fields.is_empty()) {
+        (Some(_), false) => Err(PngError::ConflictingPayload),
+        (None, true) => Err(PngError::MissingPayload),
+        (Some(message), true) => Ok(message.clone().into_bytes()),
+        (None, false) => {
+            let mut payload = Payload::new();
+            for field in &args.fields {
+                let (name, value) = field
+                    .split_once('=')
+                    .ok_or_else(|| PngError::InvalidField(field.clone()))?;
+                payload.push(name, value.as_bytes().to_vec());
+            }
+
+            payload.to_bytes()
+        }
+    }
+}
 
-    let decoded_chunk = png.chunk_by_type(&args.chunk_type);
+pub fn run_decode(args: &DecodeArgs) -> Result<(), PngError> {
+    let file_bytes = fs::read(&args.file_path)?;
 
-    match decoded_chunk {
-        Some(chunk) => println!("{}", chunk),
-        None => println!("That chunk doesn't exist"),
+    let png = if args.no_verify {
+        Png::try_from_trusted(file_bytes.as_slice())?
+    } else {
+        Png::try_from(file_bytes.as_slice())?
+    };
+
+    let chunk = match png.chunk_by_type(&args.chunk_type) {
+        Some(chunk) => chunk,
+        None => {
+            println!("That chunk doesn't exist");
+            return Ok(());
+        }
+    };
+
+    match Payload::from_bytes(chunk.data()) {
+        Ok(payload) => match &args.field {
+            Some(name) => {
+                let value = payload
+                    .get(name)
+                    .ok_or_else(|| PngError::MissingField(name.clone()))?;
+                println!("{}", String::from_utf8_lossy(value));
+            }
+            None => {
+                for entry in payload.entries() {
+                    println!("{}: {}", entry.name, String::from_utf8_lossy(&entry.value));
+                }
+            }
+        },
+        Err(PngError::NotAPayload) if args.field.is_none() => println!("{}", chunk),
+        Err(err) => return Err(err),
     }
 
     Ok(())
 }
 
-pub fn run_remove(args: &RemoveArgs) -> Result<(), Error> {
+pub fn run_remove(args: &RemoveArgs) -> Result<(), PngError> {
     let file_bytes = fs::read(&args.file_path)?;
 
     let mut png = Png::try_from(file_bytes.as_slice())?;
@@ -52,12 +101,108 @@ pub fn run_remove(args: &RemoveArgs) -> Result<(), Error> {
     Ok(())
 }
 
-pub fn run_print(args: &PrintArgs) -> Result<(), Error> {
+pub fn run_print(args: &PrintArgs) -> Result<(), PngError> {
     let file_bytes = fs::read(&args.file_path)?;
 
-    let png = Png::try_from(file_bytes.as_slice())?;
+    let png = if args.no_verify {
+        Png::try_from_trusted(file_bytes.as_slice())?
+    } else {
+        Png::try_from(file_bytes.as_slice())?
+    };
 
     println!("{}", png);
 
     Ok(())
 }
+
+pub fn run_batch(args: &BatchArgs) -> Result<(), PngError> {
+    let file_bytes = fs::read(&args.file_path)?;
+    let png = Png::try_from(file_bytes.as_slice())?;
+
+    let output = fs::File::create(&args.output_file)?;
+    let mut stream = PngStream::new(BufWriter::new(output))?;
+
+    for chunk in png.chunks() {
+        stream.append_chunk(chunk.clone())?;
+    }
+
+    let pairs: Box<dyn BufRead> = match &args.pairs_file {
+        Some(path) => Box::new(BufReader::new(fs::File::open(path)?)),
+        None => Box::new(BufReader::new(io::stdin())),
+    };
+
+    for line in pairs.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (chunk_type, message) = line
+            .split_once(' ')
+            .ok_or_else(|| PngError::InvalidField(line.clone()))?;
+        let chunk_type = ChunkType::from_str(chunk_type)?;
+        let new_chunk = Chunk::new(chunk_type, message.as_bytes().to_vec());
+
+        stream.append_chunk(new_chunk)?;
+    }
+
+    stream.finalize()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn encode_args(message: Option<&str>, fields: &[&str]) -> EncodeArgs {
+        EncodeArgs {
+            file_path: PathBuf::from("in.png"),
+            chunk_type: "ruSt".to_string(),
+            message: message.map(str::to_string),
+            fields: fields.iter().map(|field| field.to_string()).collect(),
+            output_file: None,
+        }
+    }
+
+    #[test]
+    fn test_encode_payload_rejects_message_and_field_together() {
+        let args = encode_args(Some("hello"), &["user=ferris"]);
+        assert!(matches!(
+            encode_payload(&args),
+            Err(PngError::ConflictingPayload)
+        ));
+    }
+
+    #[test]
+    fn test_encode_payload_rejects_neither_message_nor_field() {
+        let args = encode_args(None, &[]);
+        assert!(matches!(encode_payload(&args), Err(PngError::MissingPayload)));
+    }
+
+    #[test]
+    fn test_encode_payload_rejects_field_without_equals() {
+        let args = encode_args(None, &["user"]);
+        assert!(matches!(
+            encode_payload(&args),
+            Err(PngError::InvalidField(field)) if field == "user"
+        ));
+    }
+
+    #[test]
+    fn test_encode_payload_builds_payload_from_fields() {
+        let args = encode_args(None, &["user=ferris"]);
+        let bytes = encode_payload(&args).unwrap();
+
+        let payload = Payload::from_bytes(&bytes).unwrap();
+        assert_eq!(payload.get("user"), Some("ferris".as_bytes()));
+    }
+
+    #[test]
+    fn test_encode_payload_uses_message_as_is() {
+        let args = encode_args(Some("hello"), &[]);
+        let bytes = encode_payload(&args).unwrap();
+        assert_eq!(bytes, b"hello".to_vec());
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+use std::string::FromUtf8Error;
+
+/// Errors produced while parsing or manipulating PNG chunks.
+#[derive(Debug)]
+pub enum PngError {
+    /// The input ended before a complete chunk or `Payload` record could be read.
+    TooShort,
+    /// The CRC stored in a chunk did not match the CRC computed over its bytes.
+    CrcMismatch { expected: u32, found: u32 },
+    /// A chunk type's bytes are not all ASCII letters as required by the PNG spec.
+    InvalidChunkType([u8; 4]),
+    /// A chunk with the requested type could not be found.
+    MissingChunk(String),
+    /// The file did not start with the standard 8-byte PNG signature.
+    BadSignature,
+    /// A field name is too long to fit in a `Payload` record's one-byte tag.
+    FieldNameTooLong(String),
+    /// A `--field` argument was not in the `name=value` form, or a `batch`
+    /// input line was not in the `chunk_type message` form.
+    InvalidField(String),
+    /// Neither a positional `message` nor any `--field` was given to `encode`.
+    MissingPayload,
+    /// Both a positional `message` and `--field` were given to `encode`.
+    ConflictingPayload,
+    /// A single named field was requested from a chunk that has no such field.
+    MissingField(String),
+    /// A chunk's data was not in the `Payload` TLV format (missing magic tag).
+    NotAPayload,
+    /// A chunk was appended to, or `finalize` was called again on, a
+    /// [`crate::png::PngStream`] that was already finalized.
+    StreamFinalized,
+    Io(std::io::Error),
+    Utf8(FromUtf8Error),
+}
+
+impl fmt::Display for PngError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PngError::TooShort => {
+                write!(f, "input ended before a complete chunk or record was read")
+            }
+            PngError::CrcMismatch { expected, found } => {
+                write!(f, "crc mismatch: expected {expected}, found {found}")
+            }
+            PngError::InvalidChunkType(bytes) => {
+                write!(f, "invalid chunk type bytes: {bytes:?}")
+            }
+            PngError::MissingChunk(chunk_type) => {
+                write!(f, "chunk of type \"{chunk_type}\" was not found")
+            }
+            PngError::BadSignature => write!(f, "file does not start with the PNG signature"),
+            PngError::FieldNameTooLong(name) => {
+                write!(f, "field name \"{name}\" is longer than 255 bytes")
+            }
+            PngError::InvalidField(field) => {
+                write!(f, "\"{field}\" is not in the form name=value or chunk_type message")
+            }
+            PngError::StreamFinalized => {
+                write!(f, "PngStream was already finalized")
+            }
+            PngError::MissingPayload => {
+                write!(f, "encode needs a message or at least one --field")
+            }
+            PngError::ConflictingPayload => {
+                write!(f, "encode accepts a message or --field, not both")
+            }
+            PngError::MissingField(name) => {
+                write!(f, "field \"{name}\" was not found in the chunk's payload")
+            }
+            PngError::NotAPayload => {
+                write!(f, "chunk data is not in the Payload TLV format")
+            }
+            PngError::Io(err) => write!(f, "{err}"),
+            PngError::Utf8(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for PngError {}
+
+impl From<std::io::Error> for PngError {
+    fn from(err: std::io::Error) -> Self {
+        PngError::Io(err)
+    }
+}
+
+impl From<FromUtf8Error> for PngError {
+    fn from(err: FromUtf8Error) -> Self {
+        PngError::Utf8(err)
+    }
+}
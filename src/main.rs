@@ -1,11 +1,13 @@
 use self::args::{Cli, Commands};
-use self::commands::{run_decode, run_encode, run_print, run_remove};
+use self::commands::{run_batch, run_decode, run_encode, run_print, run_remove};
 use clap::Parser;
 
 mod args;
 mod chunk;
 mod chunk_type;
 mod commands;
+mod error;
+mod payload;
 mod png;
 
 pub type Error = Box<dyn std::error::Error>;
@@ -19,6 +21,7 @@ fn main() -> Result<()> {
         Commands::Decode(args) => run_decode(args)?,
         Commands::Remove(args) => run_remove(args)?,
         Commands::Print(args) => run_print(args)?,
+        Commands::Batch(args) => run_batch(args)?,
     }
 
     Ok(())
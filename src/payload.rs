@@ -0,0 +1,157 @@
+use crate::error::PngError;
+
+/// A single named entry inside a [`Payload`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadEntry {
+    pub name: String,
+    pub value: Vec<u8>,
+}
+
+/// 4-byte tag written before the TLV records, so `from_bytes` can tell a
+/// `Payload` apart from an arbitrary plain-message chunk instead of guessing
+/// from whether the bytes happen to parse.
+const MAGIC: &[u8; 4] = b"PLD1";
+
+/// An ordered list of named byte values, packed into a chunk's data as a
+/// [`MAGIC`] tag followed by a sequence of self-describing TLV records:
+/// `tag: u8` (the name's length), `len: u32` big-endian (the combined
+/// length of the name and value), then `len` bytes holding the name
+/// followed by the value.
+///
+/// This lets a single chunk carry several named secrets instead of one
+/// opaque blob, and round-trips without ambiguity.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Payload {
+    entries: Vec<PayloadEntry>,
+}
+
+impl Payload {
+    pub fn new() -> Self {
+        Payload::default()
+    }
+
+    pub fn push(&mut self, name: impl Into<String>, value: impl Into<Vec<u8>>) {
+        self.entries.push(PayloadEntry {
+            name: name.into(),
+            value: value.into(),
+        });
+    }
+
+    pub fn entries(&self) -> &[PayloadEntry] {
+        &self.entries
+    }
+
+    pub fn get(&self, name: &str) -> Option<&[u8]> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(|entry| entry.value.as_slice())
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, PngError> {
+        let mut out = MAGIC.to_vec();
+
+        for entry in &self.entries {
+            let name_bytes = entry.name.as_bytes();
+            let tag: u8 = name_bytes
+                .len()
+                .try_into()
+                .map_err(|_| PngError::FieldNameTooLong(entry.name.clone()))?;
+
+            let record_len = (name_bytes.len() + entry.value.len()) as u32;
+
+            out.push(tag);
+            out.extend_from_slice(&record_len.to_be_bytes());
+            out.extend_from_slice(name_bytes);
+            out.extend_from_slice(&entry.value);
+        }
+
+        Ok(out)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PngError> {
+        let Some(mut remaining) = bytes.strip_prefix(MAGIC.as_slice()) else {
+            return Err(PngError::NotAPayload);
+        };
+
+        let mut entries = Vec::new();
+
+        while !remaining.is_empty() {
+            if remaining.len() < 5 {
+                return Err(PngError::TooShort);
+            }
+
+            let name_len = remaining[0] as usize;
+            let record_len = u32::from_be_bytes(remaining[1..5].try_into().unwrap()) as usize;
+
+            if record_len < name_len || remaining.len() < 5 + record_len {
+                return Err(PngError::TooShort);
+            }
+
+            let record = &remaining[5..5 + record_len];
+            let name = String::from_utf8(record[..name_len].to_vec())?;
+            let value = record[name_len..].to_vec();
+
+            entries.push(PayloadEntry { name, value });
+            remaining = &remaining[5 + record_len..];
+        }
+
+        Ok(Payload { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut payload = Payload::new();
+        payload.push("user", "ferris".as_bytes().to_vec());
+        payload.push("password", "hunter2".as_bytes().to_vec());
+
+        let bytes = payload.to_bytes().unwrap();
+        let decoded = Payload::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn test_preserves_insertion_order() {
+        let mut payload = Payload::new();
+        payload.push("first", Vec::new());
+        payload.push("second", Vec::new());
+        payload.push("third", Vec::new());
+
+        let bytes = payload.to_bytes().unwrap();
+        let decoded = Payload::from_bytes(&bytes).unwrap();
+
+        let names: Vec<&str> = decoded.entries().iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["first", "second", "third"]);
+    }
+
+    #[test]
+    fn test_get_by_name() {
+        let mut payload = Payload::new();
+        payload.push("user", "ferris".as_bytes().to_vec());
+
+        assert_eq!(payload.get("user"), Some("ferris".as_bytes()));
+        assert_eq!(payload.get("missing"), None);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_overrunning_record() {
+        // Claims a 100-byte record but only a handful of bytes follow.
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[4u8, 0, 0, 0, 100, b't', b'e', b's', b't']);
+
+        let result = Payload::from_bytes(&bytes);
+        assert!(matches!(result, Err(PngError::TooShort)));
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_data_without_magic() {
+        let result = Payload::from_bytes(b"just a plain message");
+        assert!(matches!(result, Err(PngError::NotAPayload)));
+    }
+}
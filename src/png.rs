@@ -0,0 +1,353 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::Write;
+use std::str::FromStr;
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+use crate::error::PngError;
+
+pub struct Png {
+    chunks: Vec<Chunk>,
+}
+
+impl Png {
+    pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Png {
+        Png { chunks }
+    }
+
+    pub fn append_chunk(&mut self, chunk: Chunk) {
+        self.chunks.push(chunk);
+    }
+
+    pub fn remove_chunk(&mut self, chunk_type: &str) -> Result<Chunk, PngError> {
+        let position = self
+            .chunks
+            .iter()
+            .position(|chunk| chunk.chunk_type().to_string() == chunk_type)
+            .ok_or_else(|| PngError::MissingChunk(chunk_type.to_string()))?;
+
+        Ok(self.chunks.remove(position))
+    }
+
+    pub fn header(&self) -> &[u8; 8] {
+        &Self::STANDARD_HEADER
+    }
+
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|chunk| chunk.chunk_type().to_string() == chunk_type)
+    }
+
+    /// Writes the signature and every chunk directly to `w`, without first
+    /// building the whole image in an intermediate `Vec`. The chunks
+    /// themselves are already held in memory by this `Png`.
+    pub fn write_to<W: std::io::Write>(&self, w: &mut W) -> Result<(), PngError> {
+        w.write_all(&Self::STANDARD_HEADER)?;
+
+        for chunk in &self.chunks {
+            chunk.write_to(w)?;
+        }
+
+        Ok(())
+    }
+
+    /// Parses every chunk in `bytes` via [`Chunk::from_bytes_unchecked`], so
+    /// a PNG with many chunks pays one walk over the buffer instead of one
+    /// crc32 computation per chunk. Reach for this once a file's
+    /// provenance is already trusted, e.g. it was just written by this tool.
+    pub fn try_from_trusted(bytes: &[u8]) -> Result<Self, PngError> {
+        Self::parse(bytes, Chunk::from_bytes_unchecked)
+    }
+
+    fn parse(
+        bytes: &[u8],
+        parse_chunk: impl Fn(&[u8]) -> Result<Chunk, PngError>,
+    ) -> Result<Self, PngError> {
+        if bytes.len() < Self::STANDARD_HEADER.len() || bytes[..8] != Self::STANDARD_HEADER {
+            return Err(PngError::BadSignature);
+        }
+
+        let mut chunks = Vec::new();
+        let mut remaining = &bytes[8..];
+
+        while !remaining.is_empty() {
+            let chunk = parse_chunk(remaining)?;
+            let chunk_len = 12 + chunk.length() as usize;
+            remaining = &remaining[chunk_len..];
+            chunks.push(chunk);
+        }
+
+        Ok(Png { chunks })
+    }
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = PngError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::parse(bytes, |chunk_bytes| Chunk::try_from(chunk_bytes))
+    }
+}
+
+/// Builds a PNG onto an `io::Write` one chunk at a time, flushing each to
+/// disk as it arrives instead of holding every chunk in memory at once.
+///
+/// `IEND` is buffered rather than written immediately, so callers may
+/// `append_chunk` in any order and `IEND` will still end up last when the
+/// stream is [`finalize`d](PngStream::finalize).
+pub struct PngStream<W: Write> {
+    writer: Option<W>,
+    iend: Option<Chunk>,
+}
+
+impl<W: Write> PngStream<W> {
+    pub fn new(mut writer: W) -> Result<Self, PngError> {
+        writer.write_all(&Png::STANDARD_HEADER)?;
+
+        Ok(PngStream {
+            writer: Some(writer),
+            iend: None,
+        })
+    }
+
+    /// Writes `chunk` to the underlying writer, unless it is an `IEND`
+    /// chunk, in which case it is held back until [`finalize`](Self::finalize).
+    pub fn append_chunk(&mut self, chunk: Chunk) -> Result<(), PngError> {
+        let writer = self.writer.as_mut().ok_or(PngError::StreamFinalized)?;
+
+        if chunk.chunk_type().to_string() == "IEND" {
+            self.iend = Some(chunk);
+            return Ok(());
+        }
+
+        chunk.write_to(writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Writes the trailing `IEND` chunk. Further calls to
+    /// [`append_chunk`](Self::append_chunk) or `finalize` fail with
+    /// [`PngError::StreamFinalized`].
+    pub fn finalize(&mut self) -> Result<(), PngError> {
+        let mut writer = self.writer.take().ok_or(PngError::StreamFinalized)?;
+        let iend = self.iend.take().unwrap_or_else(default_iend_chunk);
+
+        iend.write_to(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+fn default_iend_chunk() -> Chunk {
+    let chunk_type = ChunkType::from_str("IEND").expect("IEND is a valid chunk type");
+    Chunk::new(chunk_type, Vec::new())
+}
+
+impl fmt::Display for Png {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Png {{")?;
+        for chunk in &self.chunks {
+            writeln!(f, "  {chunk},")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn testing_chunks() -> Vec<Chunk> {
+        vec![
+            chunk_from_strings("FrSt", "I am the first chunk").unwrap(),
+            chunk_from_strings("miDl", "I am another chunk").unwrap(),
+            chunk_from_strings("LASt", "I am the last chunk").unwrap(),
+        ]
+    }
+
+    fn chunk_from_strings(chunk_type: &str, data: &str) -> Result<Chunk, PngError> {
+        let chunk_type = ChunkType::from_str(chunk_type)?;
+        let data: Vec<u8> = data.bytes().collect();
+
+        Ok(Chunk::new(chunk_type, data))
+    }
+
+    fn chunk_bytes(chunk: &Chunk) -> Vec<u8> {
+        let mut written = Vec::new();
+        chunk.write_to(&mut written).unwrap();
+        written
+    }
+
+    fn png_bytes(png: &Png) -> Vec<u8> {
+        let mut written = Vec::new();
+        png.write_to(&mut written).unwrap();
+        written
+    }
+
+    fn testing_png() -> Png {
+        let chunks = testing_chunks();
+        Png::from_chunks(chunks)
+    }
+
+    #[test]
+    fn test_from_chunks() {
+        let chunks = testing_chunks();
+        let png = Png::from_chunks(chunks);
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_valid_from_bytes() {
+        let chunks: Vec<u8> = testing_chunks()
+            .iter()
+            .flat_map(chunk_bytes)
+            .collect();
+
+        let bytes: Vec<u8> = Png::STANDARD_HEADER
+            .iter()
+            .chain(chunks.iter())
+            .copied()
+            .collect();
+
+        let png = Png::try_from(bytes.as_ref());
+
+        assert!(png.is_ok());
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let mut bytes = vec![13, 80, 78, 71, 13, 10, 26, 10];
+
+        bytes.extend(testing_chunks().iter().flat_map(chunk_bytes));
+
+        let png = Png::try_from(bytes.as_ref());
+
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_invalid_chunk() {
+        let mut bytes = Png::STANDARD_HEADER.to_vec();
+
+        bytes.extend(&[0, 0, 0, 1, 66, 66, 66, 66, 1, 2, 3, 4, 5, 6]);
+
+        let png = Png::try_from(bytes.as_ref());
+
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_append_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("TeSt", "Message").unwrap());
+        assert_eq!(png.chunk_by_type("TeSt").unwrap().to_string(), "Message");
+    }
+
+    #[test]
+    fn test_remove_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("TeSt", "Message").unwrap());
+        png.remove_chunk("TeSt").unwrap();
+        assert!(png.chunk_by_type("TeSt").is_none());
+    }
+
+    #[test]
+    fn test_remove_missing_chunk() {
+        let mut png = testing_png();
+        assert!(png.remove_chunk("TeSt").is_err());
+    }
+
+    #[test]
+    fn test_write_to_round_trip() {
+        let png = testing_png();
+        let bytes = png_bytes(&png);
+        let round_tripped = Png::try_from(bytes.as_ref()).unwrap();
+        assert_eq!(round_tripped.chunks().len(), png.chunks().len());
+    }
+
+    #[test]
+    fn test_try_from_trusted_ignores_bad_crc() {
+        let mut bytes = chunk_bytes(&chunk_from_strings("TeSt", "hello").unwrap());
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF; // corrupt the CRC
+
+        let bytes: Vec<u8> = Png::STANDARD_HEADER
+            .iter()
+            .chain(bytes.iter())
+            .copied()
+            .collect();
+
+        assert!(Png::try_from(bytes.as_ref()).is_err());
+        assert!(Png::try_from_trusted(bytes.as_ref()).is_ok());
+    }
+
+    #[test]
+    fn test_png_write_to_is_deterministic() {
+        let png = testing_png();
+
+        let first = png_bytes(&png);
+        let second = png_bytes(&png);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_png_stream_keeps_iend_last() {
+        let mut out = Vec::new();
+        let mut stream = PngStream::new(&mut out).unwrap();
+
+        stream
+            .append_chunk(chunk_from_strings("IEND", "").unwrap())
+            .unwrap();
+        stream
+            .append_chunk(chunk_from_strings("TeSt", "hello").unwrap())
+            .unwrap();
+        stream.finalize().unwrap();
+
+        let png = Png::try_from(out.as_ref()).unwrap();
+        assert_eq!(png.chunks().last().unwrap().chunk_type().to_string(), "IEND");
+        assert_eq!(png.chunks()[0].chunk_type().to_string(), "TeSt");
+    }
+
+    #[test]
+    fn test_png_stream_adds_iend_if_missing() {
+        let mut out = Vec::new();
+        let mut stream = PngStream::new(&mut out).unwrap();
+
+        stream
+            .append_chunk(chunk_from_strings("TeSt", "hello").unwrap())
+            .unwrap();
+        stream.finalize().unwrap();
+
+        let png = Png::try_from(out.as_ref()).unwrap();
+        assert_eq!(png.chunks().last().unwrap().chunk_type().to_string(), "IEND");
+    }
+
+    #[test]
+    fn test_png_stream_rejects_append_after_finalize() {
+        let mut out = Vec::new();
+        let mut stream = PngStream::new(&mut out).unwrap();
+        stream.finalize().unwrap();
+
+        let result = stream.append_chunk(chunk_from_strings("TeSt", "hello").unwrap());
+        assert!(matches!(result, Err(PngError::StreamFinalized)));
+    }
+
+    #[test]
+    fn test_png_trait_impls() {
+        let png = testing_png();
+        let _png_string = format!("{png}");
+    }
+}